@@ -5,11 +5,13 @@ use std::str::FromStr;
 
 use anyhow::{Context, Result as AnyhowResult};
 use clap::Parser as ClapParser;
-use chrono::{DateTime, Duration, NaiveDateTime, Timelike, TimeZone, Utc};
+use chrono::{Datelike, DateTime, Duration, LocalResult, NaiveDate, NaiveDateTime, Timelike, TimeZone, Utc, Weekday};
 use chrono_tz::Tz;
 use colored::Colorize;
 use ical::IcalParser;
+use ical::property::Property;
 use prettytable::{format, row, Table};
+use pure_rust_locales::Locale;
 
 // CLI Args
 #[derive(ClapParser, Debug)]
@@ -31,45 +33,445 @@ struct Args {
     /// Buffer minutes for transitions (default: 15)
     #[arg(short = 'b', long, default_value_t = 15)]
     buffer_mins: i64,
-    /// Timezone for output and search (IANA, e.g., America/New_York; default: UTC)
-    #[arg(short, long, default_value = "UTC")]
-    timezone: String,
-}
-
-// Helper to parse ICS datetime string (e.g., "19960918T143000Z") to NaiveDateTime (UTC)
-fn parse_ics_datetime(s: &str) -> Option<NaiveDateTime> {
-    if s.ends_with('Z') {
-        let without_z = &s[0..s.len() - 1];
-        if without_z.len() == 15 {  // YYYYMMDDTHHMMSS
-            let year = &without_z[0..4];
-            let month = &without_z[4..6];
-            let day = &without_z[6..8];
-            let hour = &without_z[9..11];
-            let min = &without_z[11..13];
-            let sec = &without_z[13..15];
-            let formatted = format!("{}-{}-{}T{}:{}:{}Z", year, month, day, hour, min, sec);
-            if let Ok(dt) = DateTime::parse_from_rfc3339(&formatted) {
-                return Some(dt.with_timezone(&Utc).naive_utc());
+    /// Timezone for output and search (IANA, e.g., America/New_York; default: auto-detected from the machine, falling back to UTC)
+    #[arg(short, long)]
+    timezone: Option<String>,
+    /// Output format: table, html, ics (top suggested slots as VEVENTs), or freebusy
+    /// (a single VFREEBUSY aggregating free windows in the range) (default: table)
+    #[arg(long, value_enum, default_value = "table")]
+    output: OutputFormat,
+    /// Privacy level for HTML busy blocks: show only "Busy" or the event summary (default: public)
+    #[arg(long, value_enum, default_value = "public")]
+    privacy: Privacy,
+    /// Write output to this file instead of stdout (used by --output html, ics and freebusy)
+    #[arg(long)]
+    out: Option<PathBuf>,
+    /// Start of the search window: an ISO datetime (e.g. "2024-06-10T09:00:00") or a
+    /// relative expression ("next monday", "in 3 days", "+2 weeks"). Overrides the
+    /// implicit "tomorrow" start when set.
+    #[arg(long)]
+    from: Option<String>,
+    /// End of the search window, same formats as --from. Overrides --days-ahead when set.
+    #[arg(long)]
+    until: Option<String>,
+    /// Anchor the search window to this file's modification time instead of "now"
+    /// (e.g. when the file is a candidate's freshly-exported .ics)
+    #[arg(long)]
+    reference: Option<PathBuf>,
+    /// Locale for weekday/month names and time formatting (e.g. fr_FR, de_DE); default:
+    /// English/ISO. Unrecognized locales fall back to the default.
+    #[arg(long)]
+    locale: Option<String>,
+}
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq)]
+enum OutputFormat {
+    Table,
+    Html,
+    Ics,
+    Freebusy,
+}
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq)]
+enum Privacy {
+    Public,
+    Private,
+}
+
+// Resolve a naive local datetime in `tz` to a UTC instant, handling DST gaps/overlaps
+// by always picking the earliest valid instant so the slot stays conservatively blocked.
+fn resolve_local_datetime(naive: NaiveDateTime, tz: Tz) -> NaiveDateTime {
+    match tz.from_local_datetime(&naive) {
+        LocalResult::Single(dt) => dt.with_timezone(&Utc).naive_utc(),
+        LocalResult::Ambiguous(earliest, _latest) => earliest.with_timezone(&Utc).naive_utc(),
+        LocalResult::None => {
+            // Spring-forward gap: nudge forward until we land on a valid instant.
+            let mut probe = naive;
+            for _ in 0..24 {
+                probe += Duration::hours(1);
+                if let LocalResult::Single(dt) = tz.from_local_datetime(&probe) {
+                    return dt.with_timezone(&Utc).naive_utc();
+                }
             }
+            naive
+        }
+    }
+}
+
+fn find_param<'a>(prop: &'a Property, key: &str) -> Option<&'a str> {
+    prop.params.as_ref()?
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(key))
+        .and_then(|(_, values)| values.first())
+        .map(|v| v.as_str())
+}
+
+// Parse an ICS datetime property value into its wall-clock NaiveDateTime plus the zone
+// it should be resolved in (UTC for a trailing "Z", the TZID param's zone, or `local_tz`
+// for a floating time / an all-day `VALUE=DATE`). Kept separate from the UTC-resolved
+// form so RRULE expansion can step in wall-clock time and stay DST-correct.
+fn ics_datetime_parts(prop: &Property, local_tz: Tz) -> Option<(NaiveDateTime, Tz)> {
+    let value = prop.value.as_ref()?;
+
+    // All-day event: VALUE=DATE, "YYYYMMDD".
+    if value.len() == 8 && value.bytes().all(|b| b.is_ascii_digit()) {
+        let date = NaiveDate::parse_from_str(value, "%Y%m%d").ok()?;
+        return Some((date.and_hms_opt(0, 0, 0)?, local_tz));
+    }
+
+    if let Some(without_z) = value.strip_suffix('Z') {
+        if without_z.len() != 15 {
+            return None;
+        }
+        let naive = NaiveDateTime::parse_from_str(without_z, "%Y%m%dT%H%M%S").ok()?;
+        return Some((naive, Tz::UTC));
+    }
+
+    if value.len() != 15 {
+        return None;
+    }
+    let naive = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok()?;
+
+    if let Some(tzid) = find_param(prop, "TZID") {
+        let tz = Tz::from_str(tzid).ok()?;
+        return Some((naive, tz));
+    }
+
+    // No "Z" and no TZID: a floating local time, interpreted in the output timezone.
+    Some((naive, local_tz))
+}
+
+// Parse an ICS datetime property value (e.g., "19960918T143000Z", a TZID-anchored local
+// time, a floating local time, or a `VALUE=DATE` all-day date) to NaiveDateTime (UTC).
+fn parse_ics_datetime(prop: &Property, local_tz: Tz) -> Option<NaiveDateTime> {
+    let (naive, zone) = ics_datetime_parts(prop, local_tz)?;
+    Some(resolve_local_datetime(naive, zone))
+}
+
+// Safety valve for RRULEs with no UNTIL/COUNT (or a pathologically large one).
+const MAX_RECURRENCE_OCCURRENCES: usize = 2000;
+
+fn weekday_from_byday(code: &str) -> Option<Weekday> {
+    match code {
+        "MO" => Some(Weekday::Mon),
+        "TU" => Some(Weekday::Tue),
+        "WE" => Some(Weekday::Wed),
+        "TH" => Some(Weekday::Thu),
+        "FR" => Some(Weekday::Fri),
+        "SA" => Some(Weekday::Sat),
+        "SU" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+// Parse an RRULE's UNTIL value to a UTC instant. Per RFC 5545, UNTIL shares DTSTART's
+// value type: a trailing "Z" means it's already UTC; otherwise it's a wall-clock time in
+// DTSTART's own zone (`zone`), which is the zone we must resolve it through.
+fn parse_until(value: &str, zone: Tz) -> Option<NaiveDateTime> {
+    if let Some(without_z) = value.strip_suffix('Z') {
+        if without_z.len() != 15 {
+            return None;
         }
+        return NaiveDateTime::parse_from_str(without_z, "%Y%m%dT%H%M%S").ok();
+    }
+    if value.len() == 15 {
+        let naive = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok()?;
+        return Some(resolve_local_datetime(naive, zone));
+    }
+    if value.len() == 8 {
+        let date = NaiveDate::parse_from_str(value, "%Y%m%d").ok()?;
+        return Some(resolve_local_datetime(date.and_hms_opt(0, 0, 0)?, zone));
     }
     None
 }
 
+// Expand an RRULE into concrete occurrence start times (UTC), bounded by `end_search`,
+// UNTIL, COUNT, and a hard cap so an open-ended rule can't run away. `dtstart_local` is
+// DTSTART's wall-clock time in `zone` (its own TZID, or UTC/local_tz as appropriate);
+// stepping happens on the wall clock and each occurrence is re-resolved through `zone`
+// so DST transitions shift the UTC instant instead of silently drifting the local time.
+fn expand_rrule(
+    rrule: &str,
+    dtstart_local: NaiveDateTime,
+    zone: Tz,
+    end_search: NaiveDateTime,
+) -> Vec<NaiveDateTime> {
+    let mut freq = None;
+    let mut interval: i64 = 1;
+    let mut count: Option<usize> = None;
+    let mut until: Option<NaiveDateTime> = None;
+    let mut byday: Vec<Weekday> = Vec::new();
+
+    for part in rrule.split(';') {
+        let mut kv = part.splitn(2, '=');
+        let (key, value) = match (kv.next(), kv.next()) {
+            (Some(k), Some(v)) => (k, v),
+            _ => continue,
+        };
+        match key {
+            "FREQ" => freq = Some(value.to_string()),
+            "INTERVAL" => interval = value.parse().unwrap_or(1),
+            "COUNT" => count = value.parse().ok(),
+            "UNTIL" => until = parse_until(value, zone),
+            "BYDAY" => byday = value.split(',').filter_map(weekday_from_byday).collect(),
+            _ => {}
+        }
+    }
+
+    let freq = match freq.as_deref() {
+        Some(f) => f,
+        None => return vec![resolve_local_datetime(dtstart_local, zone)],
+    };
+
+    let mut occurrences = Vec::new();
+    let mut current = dtstart_local;
+    loop {
+        if occurrences.len() >= MAX_RECURRENCE_OCCURRENCES {
+            break;
+        }
+        if let Some(max) = count {
+            if occurrences.len() >= max {
+                break;
+            }
+        }
+
+        let occ_utc = resolve_local_datetime(current, zone);
+        if occ_utc > end_search {
+            break;
+        }
+        if let Some(u) = until {
+            if occ_utc > u {
+                break;
+            }
+        }
+
+        if byday.is_empty() || byday.contains(&current.weekday()) {
+            occurrences.push(occ_utc);
+        }
+
+        current = match freq {
+            "DAILY" => current + Duration::days(interval),
+            "WEEKLY" => {
+                if byday.is_empty() {
+                    current + Duration::weeks(interval)
+                } else {
+                    // Walk day by day within the week, jumping `interval` weeks once we
+                    // wrap past Sunday so multi-BYDAY weekly rules hit every listed day.
+                    let next = current + Duration::days(1);
+                    if next.weekday() == Weekday::Mon && interval > 1 {
+                        next + Duration::weeks(interval - 1)
+                    } else {
+                        next
+                    }
+                }
+            }
+            "MONTHLY" => {
+                // Keep advancing by `interval` months, skipping any month that doesn't
+                // have this day-of-month (e.g. the 31st in February), instead of
+                // terminating the whole expansion at the first such gap.
+                let mut months_ahead = interval as i32;
+                let mut next = None;
+                for _ in 0..48 {
+                    let total_months = current.year() * 12 + current.month0() as i32 + months_ahead;
+                    let year = total_months.div_euclid(12);
+                    let month0 = total_months.rem_euclid(12);
+                    if let Some(d) = NaiveDate::from_ymd_opt(year, month0 as u32 + 1, current.day()) {
+                        next = Some(d.and_time(current.time()));
+                        break;
+                    }
+                    months_ahead += interval as i32;
+                }
+                match next {
+                    Some(d) => d,
+                    None => break, // day-of-month never valid in 48 steps: give up
+                }
+            }
+            _ => break, // unsupported FREQ: stop expanding
+        };
+    }
+
+    occurrences
+}
+
+// Stamp (hour, min, sec) onto the local calendar day containing `utc_naive` and resolve
+// the result back through `tz`, so a clamp like "end of day at end_hour" lands on the
+// user's local wall clock instead of drifting by the zone's UTC offset.
+fn local_day_at(utc_naive: NaiveDateTime, tz: Tz, hour: u32, min: u32, sec: u32) -> Option<NaiveDateTime> {
+    let local_date = Utc.from_utc_datetime(&utc_naive).with_timezone(&tz).date_naive();
+    Some(resolve_local_datetime(local_date.and_hms_opt(hour, min, sec)?, tz))
+}
+
+// Drop any expanded RRULE occurrence whose start matches an EXDATE.
+fn apply_exdates(occurrences: Vec<NaiveDateTime>, exdates: &[NaiveDateTime]) -> Vec<NaiveDateTime> {
+    occurrences.into_iter().filter(|occ| !exdates.contains(occ)).collect()
+}
+
+fn resolve_locale(code: &str) -> Option<Locale> {
+    match code {
+        "fr_FR" => Some(Locale::fr_FR),
+        "de_DE" => Some(Locale::de_DE),
+        _ => None,
+    }
+}
+
+// Localized text for the hard-coded slot labels; unrecognized locales fall back to English.
+struct Strings {
+    morning_peak: &'static str,
+    slot_label: &'static str,
+    no_slots: &'static str,
+}
+
+const EN_STRINGS: Strings = Strings {
+    morning_peak: " (Morning Peak!)",
+    slot_label: "30 mins",
+    no_slots: "No free slots found—try adjusting hours, range, or timezone!",
+};
+
+fn strings_for_locale(code: Option<&str>) -> Strings {
+    match code {
+        Some("fr_FR") => Strings {
+            morning_peak: " (pic du matin !)",
+            slot_label: "30 min",
+            no_slots: "Aucun créneau libre trouvé—essayez d'ajuster les horaires, la période ou le fuseau horaire !",
+        },
+        Some("de_DE") => Strings {
+            morning_peak: " (Morgen-Hochphase!)",
+            slot_label: "30 Min.",
+            no_slots: "Keine freien Termine gefunden—versuchen Sie es mit anderen Zeiten, einem anderen Zeitraum oder einer anderen Zeitzone!",
+        },
+        _ => EN_STRINGS,
+    }
+}
+
+// Format a local datetime using the resolved locale if one was given and recognized;
+// otherwise fall back to the default English/ISO formatting (unchanged behavior).
+fn format_local_dt(dt: &chrono::DateTime<Tz>, fmt: &str, locale: Option<&str>) -> String {
+    match locale.and_then(resolve_locale) {
+        Some(loc) => dt.format_localized(fmt, loc).to_string(),
+        None => dt.format(fmt).to_string(),
+    }
+}
+
+fn weekday_from_name(name: &str) -> Option<Weekday> {
+    match name {
+        "monday" => Some(Weekday::Mon),
+        "tuesday" => Some(Weekday::Tue),
+        "wednesday" => Some(Weekday::Wed),
+        "thursday" => Some(Weekday::Thu),
+        "friday" => Some(Weekday::Fri),
+        "saturday" => Some(Weekday::Sat),
+        "sunday" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+// Parse a unit string like "day"/"days"/"week"/"weeks"/"hour"/"hours" into a Duration.
+fn duration_from_unit(n: i64, unit: &str) -> Option<Duration> {
+    match unit.trim().trim_end_matches('s') {
+        "hour" => Some(Duration::hours(n)),
+        "day" => Some(Duration::days(n)),
+        "week" => Some(Duration::weeks(n)),
+        _ => None,
+    }
+}
+
+// Parse an ISO datetime/date, or a relative expression ("next monday", "in 3 days",
+// "+2 weeks"), into a concrete UTC instant. Relative expressions are anchored to `now`
+// and weekday names to the calendar date of `now` in `tz`.
+fn parse_when(input: &str, tz: Tz, now: NaiveDateTime) -> Option<NaiveDateTime> {
+    let s = input.trim();
+
+    if let Ok(dt) = NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S") {
+        return Some(resolve_local_datetime(dt, tz));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+        return Some(resolve_local_datetime(date.and_hms_opt(0, 0, 0)?, tz));
+    }
+
+    let lower = s.to_lowercase();
+
+    if let Some(day_name) = lower.strip_prefix("next ") {
+        let target = weekday_from_name(day_name.trim())?;
+        let now_local_date = Utc.from_utc_datetime(&now).with_timezone(&tz).date_naive();
+        let mut day = now_local_date + Duration::days(1);
+        while day.weekday() != target {
+            day += Duration::days(1);
+        }
+        return Some(resolve_local_datetime(day.and_hms_opt(0, 0, 0)?, tz));
+    }
+
+    if let Some(rest) = lower.strip_prefix("in ") {
+        let parts: Vec<&str> = rest.split_whitespace().collect();
+        if parts.len() == 2 {
+            let n: i64 = parts[0].parse().ok()?;
+            return Some(now + duration_from_unit(n, parts[1])?);
+        }
+    }
+
+    if let Some(rest) = s.strip_prefix('+') {
+        let rest = rest.trim();
+        let split = rest.find(|c: char| !c.is_ascii_digit())?;
+        let (num_str, unit_str) = rest.split_at(split);
+        let n: i64 = num_str.parse().ok()?;
+        return Some(now + duration_from_unit(n, unit_str)?);
+    }
+
+    None
+}
+
 fn main() -> AnyhowResult<()> {
     let args = Args::parse();
 
-    // Parse timezone
-    let tz: Tz = FromStr::from_str(&args.timezone).context("Invalid timezone—use IANA like 'Europe/London' for Adam's base.")?;
+    if args.start_hour > 23 || args.end_hour > 23 {
+        anyhow::bail!("--start-hour and --end-hour must be between 0 and 23");
+    }
+
+    // Resolve timezone: explicit --timezone wins, otherwise auto-detect the machine's
+    // IANA zone, falling back to UTC if detection fails or the name can't be parsed.
+    let (tz, tz_name): (Tz, String) = match &args.timezone {
+        Some(explicit) => {
+            let parsed = Tz::from_str(explicit).context("Invalid timezone—use IANA like 'Europe/London' for Adam's base.")?;
+            (parsed, explicit.clone())
+        }
+        None => {
+            let detected = iana_time_zone::get_timezone().unwrap_or_else(|_| "UTC".to_string());
+            match Tz::from_str(&detected) {
+                Ok(parsed) => (parsed, detected),
+                Err(_) => (Tz::UTC, "UTC".to_string()),
+            }
+        }
+    };
 
-    // Start from now +1 day (skip today), in UTC
-    let now = Utc::now().naive_utc();
-    let start_search = now + Duration::days(1);
-    let mut end_search = start_search + Duration::days(args.days_ahead);
-    end_search = end_search.date().and_hms_opt(args.end_hour as u32, 59, 59).unwrap_or(now);
+    // "Now" is either the real clock or, with --reference, a calendar export's mtime—
+    // lets the window be pinned to when the candidate's .ics was generated.
+    let now = match &args.reference {
+        Some(path) => {
+            let metadata = fs::metadata(path).context(format!("Failed to read reference file: {:?}", path))?;
+            let modified = metadata.modified().context(format!("Failed to read modification time of reference file: {:?}", path))?;
+            DateTime::<Utc>::from(modified).naive_utc()
+        }
+        None => Utc::now().naive_utc(),
+    };
+
+    // --from/--until (ISO or relative) take precedence over the implicit "tomorrow" +
+    // --days-ahead window.
+    let start_search = match &args.from {
+        Some(from) => parse_when(from, tz, now)
+            .context("Could not parse --from—use an ISO datetime or a relative expression like 'next monday'.")?,
+        None => now + Duration::days(1),
+    };
+    let mut end_search = match &args.until {
+        Some(until) => parse_when(until, tz, now)
+            .context("Could not parse --until—use an ISO datetime or a relative expression like 'in 3 days'.")?,
+        None => start_search + Duration::days(args.days_ahead),
+    };
+    // Clamp to end_hour in the *local* calendar day, rather than stamping end_hour
+    // directly onto the UTC date—otherwise the clamp silently drifts by the zone's UTC
+    // offset for any non-UTC --timezone.
+    end_search = local_day_at(end_search, tz, args.end_hour as u32, 59, 59).unwrap_or(end_search);
 
     // Collect all event intervals from multiple ICS files (in UTC), skipping past events
-    let mut events: Vec<(NaiveDateTime, NaiveDateTime)> = Vec::new();
+    let mut events: Vec<(NaiveDateTime, NaiveDateTime, Option<String>)> = Vec::new();
     for ics_path in &args.ics_files {
         let file = fs::File::open(ics_path).context(format!("Failed to open .ics file: {:?}", ics_path))?;
         let reader = BufReader::new(file);
@@ -79,19 +481,51 @@ fn main() -> AnyhowResult<()> {
             let ical = calendar.context("Failed to parse .ics calendar—check format or try exporting again from Google/Outlook.")?;
             for event in ical.events {
                 // Find DTSTART and DTEND properties
-                let dtstart = event.properties.iter()
-                    .find(|prop| prop.name == "DTSTART")
-                    .and_then(|prop| prop.value.as_ref());
-                let dtend = event.properties.iter()
-                    .find(|prop| prop.name == "DTEND")
-                    .and_then(|prop| prop.value.as_ref());
-
-                if let (Some(start_str), Some(end_str)) = (dtstart, dtend) {
-                    if let (Some(start_dt), Some(end_dt)) = (parse_ics_datetime(start_str), parse_ics_datetime(end_str)) {
-                        // Skip if event ends before search starts (past/irrelevant)
-                        if end_dt >= start_search {
-                            events.push((start_dt, end_dt));
+                let dtstart_prop = event.properties.iter().find(|prop| prop.name == "DTSTART");
+                let dtend_prop = event.properties.iter().find(|prop| prop.name == "DTEND");
+                let summary = event.properties.iter()
+                    .find(|prop| prop.name == "SUMMARY")
+                    .and_then(|prop| prop.value.clone());
+
+                let is_all_day = dtstart_prop
+                    .and_then(|prop| prop.value.as_ref())
+                    .map(|v| v.len() == 8 && v.bytes().all(|b| b.is_ascii_digit()))
+                    .unwrap_or(false);
+
+                let start_parts = dtstart_prop.and_then(|prop| ics_datetime_parts(prop, tz));
+                let start_dt = start_parts.map(|(naive, zone)| resolve_local_datetime(naive, zone));
+                let end_dt = dtend_prop
+                    .and_then(|prop| parse_ics_datetime(prop, tz))
+                    .or_else(|| {
+                        // All-day events commonly omit DTEND, defaulting to a 1-day duration.
+                        if is_all_day { start_dt.map(|s| s + Duration::days(1)) } else { None }
+                    });
+
+                if let (Some(start_dt), Some(end_dt)) = (start_dt, end_dt) {
+                    let duration = end_dt - start_dt;
+                    let rrule = event.properties.iter()
+                        .find(|prop| prop.name == "RRULE")
+                        .and_then(|prop| prop.value.as_ref());
+
+                    if let Some(rrule) = rrule {
+                        let exdates: Vec<NaiveDateTime> = event.properties.iter()
+                            .filter(|prop| prop.name == "EXDATE")
+                            .filter_map(|prop| parse_ics_datetime(prop, tz))
+                            .collect();
+
+                        // Safe to unwrap: `start_dt` above is only Some when `start_parts` is.
+                        let (dtstart_local, dtstart_zone) = start_parts.unwrap();
+                        let occurrences = expand_rrule(rrule, dtstart_local, dtstart_zone, end_search);
+                        for occ_start in apply_exdates(occurrences, &exdates) {
+                            let occ_end = occ_start + duration;
+                            // Skip if event ends before search starts (past/irrelevant)
+                            if occ_end >= start_search {
+                                events.push((occ_start, occ_end, summary.clone()));
+                            }
                         }
+                    } else if end_dt >= start_search {
+                        // Skip if event ends before search starts (past/irrelevant)
+                        events.push((start_dt, end_dt, summary));
                     }
                 }
             }
@@ -102,12 +536,14 @@ fn main() -> AnyhowResult<()> {
     let mut candidates: Vec<(NaiveDateTime, i32)> = Vec::new(); // (slot_start_utc, score)
     let slot_duration = Duration::minutes(30);
 
-    let mut current = start_search.date().and_hms_opt(args.start_hour as u32, 0, 0).unwrap_or(now);
+    // Same local-day clamp as end_search above, so the scan's starting instant actually
+    // lands on start_hour in the user's local time, not a bare UTC stamp.
+    let mut current = local_day_at(start_search, tz, args.start_hour as u32, 0, 0).unwrap_or(now);
     while current <= end_search {
         let slot_end = current + slot_duration;
 
         // Check for conflicts: overlap with buffered events
-        let has_conflict = events.iter().any(|(e_start, e_end)| {
+        let has_conflict = events.iter().any(|(e_start, e_end, _)| {
             let buffer_start = *e_start - Duration::minutes(args.buffer_mins);
             let buffer_end = *e_end + Duration::minutes(args.buffer_mins);
             current < buffer_end && slot_end > buffer_start
@@ -129,43 +565,446 @@ fn main() -> AnyhowResult<()> {
         b.1.cmp(&a.1).then(a.0.cmp(&b.0))
     });
 
-    // Output with pretty table and colors
-    let header = format!("Suggested 30-min interview slots (prioritizing mornings) in {}:", args.timezone);
-    println!("{}", header.bright_blue().bold());
-
-    if candidates.is_empty() {
-        println!("{}", "No free slots found—try adjusting hours, range, or timezone!".yellow());
-    } else {
-        let mut table = Table::new();
-        table.set_format(*format::consts::FORMAT_CLEAN);
-
-        // Add header
-        table.add_row(row![
-            "Time (Local)".bright_green().bold(),
-            "Label".bright_green().bold(),
-            "Score".bright_green().bold()
-        ]);
-
-        for (slot_utc, score) in candidates.iter().take(5) {
-            let slot_naive = *slot_utc;
-            let slot_utc_dt = Utc.from_utc_datetime(&slot_naive);
-            let local_dt = slot_utc_dt.with_timezone(&tz);
-            let time_str = local_dt.format("%Y-%m-%d %H:%M %Z").to_string();
-            let priority = if *score == 1 { " (Morning Peak!)" } else { "" };
-            let label = if priority.is_empty() {
-                String::from("30 mins")
+    match args.output {
+        OutputFormat::Table => {
+            // Output with pretty table and colors
+            let header = format!("Suggested 30-min interview slots (prioritizing mornings) in {}:", tz_name);
+            println!("{}", header.bright_blue().bold());
+
+            let strings = strings_for_locale(args.locale.as_deref());
+
+            if candidates.is_empty() {
+                println!("{}", strings.no_slots.yellow());
             } else {
-                format!("30 mins{}", priority.green())
-            };
-            let score_str = format!("(Score: {})", score).cyan();
+                let mut table = Table::new();
+                table.set_format(*format::consts::FORMAT_CLEAN);
 
-            table.add_row(row![time_str, label, score_str]);
-        }
+                // Add header
+                table.add_row(row![
+                    "Time (Local)".bright_green().bold(),
+                    "Label".bright_green().bold(),
+                    "Score".bright_green().bold()
+                ]);
+
+                for (slot_utc, score) in candidates.iter().take(5) {
+                    let slot_naive = *slot_utc;
+                    let slot_utc_dt = Utc.from_utc_datetime(&slot_naive);
+                    let local_dt = slot_utc_dt.with_timezone(&tz);
+                    // Weekday names only with a recognized locale; the default layout is unchanged.
+                    let fmt = if args.locale.as_deref().and_then(resolve_locale).is_some() {
+                        "%a %Y-%m-%d %H:%M %Z"
+                    } else {
+                        "%Y-%m-%d %H:%M %Z"
+                    };
+                    let time_str = format_local_dt(&local_dt, fmt, args.locale.as_deref());
+                    let priority = if *score == 1 { strings.morning_peak } else { "" };
+                    let label = if priority.is_empty() {
+                        String::from(strings.slot_label)
+                    } else {
+                        format!("{}{}", strings.slot_label, priority.green())
+                    };
+                    let score_str = format!("(Score: {})", score).cyan();
 
-        table.printstd();
+                    table.add_row(row![time_str, label, score_str]);
+                }
+
+                table.printstd();
 
-        println!("\n{}", "Pick one for your next talent chat—remember, a genuine conversation is the best investment. (Dale Carnegie nod)".magenta().italic());
+                println!("\n{}", "Pick one for your next talent chat—remember, a genuine conversation is the best investment. (Dale Carnegie nod)".magenta().italic());
+            }
+        }
+        OutputFormat::Html => {
+            let html = render_html_calendar(&events, &candidates, &args, tz, &tz_name);
+            if let Some(out_path) = &args.out {
+                fs::write(out_path, html).context(format!("Failed to write HTML output to {:?}", out_path))?;
+            } else {
+                println!("{}", html);
+            }
+        }
+        OutputFormat::Ics => {
+            let ics = render_ics_slots(&candidates, 5, now);
+            if let Some(out_path) = &args.out {
+                fs::write(out_path, ics).context(format!("Failed to write ICS output to {:?}", out_path))?;
+            } else {
+                print!("{}", ics);
+            }
+        }
+        OutputFormat::Freebusy => {
+            let free_windows = compute_free_windows(&events, start_search, end_search, args.buffer_mins);
+            let ics = render_ics_freebusy(&free_windows, start_search, end_search, now);
+            if let Some(out_path) = &args.out {
+                fs::write(out_path, ics).context(format!("Failed to write freebusy output to {:?}", out_path))?;
+            } else {
+                print!("{}", ics);
+            }
+        }
     }
 
     Ok(())
+}
+
+fn render_ics_calendar(body_lines: Vec<String>) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//interview-slot-suggester//EN".to_string(),
+    ];
+    lines.extend(body_lines);
+    lines.push("END:VCALENDAR".to_string());
+    lines.join("\r\n") + "\r\n"
+}
+
+// Emit the top `take` suggested slots as standalone VEVENTs so they round-trip back
+// into Google/Outlook via import or direct attachment to an invite.
+fn render_ics_slots(candidates: &[(NaiveDateTime, i32)], take: usize, now: NaiveDateTime) -> String {
+    let dtstamp = now.format("%Y%m%dT%H%M%SZ").to_string();
+    let mut lines = Vec::new();
+    for (idx, (slot_start, _)) in candidates.iter().take(take).enumerate() {
+        let slot_end = *slot_start + Duration::minutes(30);
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}-{}@interview-slot-suggester", dtstamp, idx));
+        lines.push(format!("DTSTAMP:{}", dtstamp));
+        lines.push(format!("DTSTART:{}", slot_start.format("%Y%m%dT%H%M%SZ")));
+        lines.push(format!("DTEND:{}", slot_end.format("%Y%m%dT%H%M%SZ")));
+        lines.push("SUMMARY:Interview (suggested)".to_string());
+        lines.push("END:VEVENT".to_string());
+    }
+    render_ics_calendar(lines)
+}
+
+// Merge buffered busy events and invert them against [start, end] to get free windows.
+fn compute_free_windows(
+    events: &[(NaiveDateTime, NaiveDateTime, Option<String>)],
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+    buffer_mins: i64,
+) -> Vec<(NaiveDateTime, NaiveDateTime)> {
+    let mut busy: Vec<(NaiveDateTime, NaiveDateTime)> = events.iter()
+        .map(|(e_start, e_end, _)| (*e_start - Duration::minutes(buffer_mins), *e_end + Duration::minutes(buffer_mins)))
+        .filter(|(s, e)| *e > start && *s < end)
+        .map(|(s, e)| (s.max(start), e.min(end)))
+        .collect();
+    busy.sort_by_key(|(s, _)| *s);
+
+    let mut merged: Vec<(NaiveDateTime, NaiveDateTime)> = Vec::new();
+    for (s, e) in busy {
+        if let Some(last) = merged.last_mut() {
+            if s <= last.1 {
+                last.1 = last.1.max(e);
+                continue;
+            }
+        }
+        merged.push((s, e));
+    }
+
+    let mut free = Vec::new();
+    let mut cursor = start;
+    for (s, e) in merged {
+        if cursor < s {
+            free.push((cursor, s));
+        }
+        cursor = cursor.max(e);
+    }
+    if cursor < end {
+        free.push((cursor, end));
+    }
+    free
+}
+
+// Emit a single VFREEBUSY aggregating all free windows in [start, end].
+fn render_ics_freebusy(free_windows: &[(NaiveDateTime, NaiveDateTime)], start: NaiveDateTime, end: NaiveDateTime, now: NaiveDateTime) -> String {
+    let dtstamp = now.format("%Y%m%dT%H%M%SZ").to_string();
+    let mut lines = vec!["BEGIN:VFREEBUSY".to_string()];
+    lines.push(format!("UID:{}@interview-slot-suggester", dtstamp));
+    lines.push(format!("DTSTAMP:{}", dtstamp));
+    lines.push(format!("DTSTART:{}", start.format("%Y%m%dT%H%M%SZ")));
+    lines.push(format!("DTEND:{}", end.format("%Y%m%dT%H%M%SZ")));
+    for (s, e) in free_windows {
+        lines.push(format!("FREEBUSY;FBTYPE=FREE:{}/{}", s.format("%Y%m%dT%H%M%SZ"), e.format("%Y%m%dT%H%M%SZ")));
+    }
+    lines.push("END:VFREEBUSY".to_string());
+    render_ics_calendar(lines)
+}
+
+// Escape text pulled from untrusted .ics input (e.g. SUMMARY) before it lands in HTML.
+fn html_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// Render the search window as a week-style HTML grid: one column per day, one row per
+// 30-min slot. Free cells get a "morning-peak" marker class; busy cells are greyed out
+// and, in private mode, labeled with the event's SUMMARY (public mode shows only "Busy").
+fn render_html_calendar(
+    events: &[(NaiveDateTime, NaiveDateTime, Option<String>)],
+    candidates: &[(NaiveDateTime, i32)],
+    args: &Args,
+    tz: Tz,
+    tz_name: &str,
+) -> String {
+    let score_by_slot: std::collections::HashMap<NaiveDateTime, i32> = candidates.iter().cloned().collect();
+
+    let mut day_cursor = candidates.iter().map(|(s, _)| *s).min()
+        .or_else(|| events.iter().map(|(s, _, _)| *s).min())
+        .map(|dt| dt.date())
+        .unwrap_or_else(|| Utc::now().naive_utc().date());
+    let last_day = candidates.iter().map(|(s, _)| *s).max()
+        .or_else(|| events.iter().map(|(_, e, _)| *e).max())
+        .map(|dt| dt.date())
+        .unwrap_or(day_cursor);
+
+    let mut day_list = Vec::new();
+    while day_cursor <= last_day {
+        day_list.push(day_cursor);
+        day_cursor += Duration::days(1);
+    }
+    if day_list.is_empty() {
+        day_list.push(Utc::now().naive_utc().date());
+    }
+
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Interview slots</title>\n<style>\n");
+    out.push_str("body { font-family: sans-serif; }\n");
+    out.push_str("table.grid { border-collapse: collapse; width: 100%; }\n");
+    out.push_str("table.grid th, table.grid td { border: 1px solid #ccc; padding: 4px 8px; text-align: center; }\n");
+    out.push_str(".free { background: #e6ffe6; }\n");
+    out.push_str(".free.morning-peak { background: #b3ffb3; font-weight: bold; }\n");
+    out.push_str(".busy { background: #d9d9d9; color: #666; }\n");
+    out.push_str("</style>\n</head>\n<body>\n");
+    out.push_str(&format!("<h1>Suggested interview slots ({})</h1>\n", html_escape(tz_name)));
+    out.push_str("<table class=\"grid\">\n<tr><th>Time</th>");
+    for day in &day_list {
+        let day_dt = tz.from_utc_datetime(&resolve_local_datetime(day.and_hms_opt(0, 0, 0).unwrap(), tz));
+        out.push_str(&format!("<th>{}</th>", format_local_dt(&day_dt, "%a %Y-%m-%d", args.locale.as_deref())));
+    }
+    out.push_str("</tr>\n");
+
+    let mut hour_row = args.start_hour;
+    while hour_row < args.end_hour {
+        for half in [0u32, 30u32] {
+            out.push_str(&format!("<tr><td>{:02}:{:02}</td>", hour_row, half));
+            for day in &day_list {
+                let local_naive = day.and_hms_opt(hour_row, half, 0).unwrap();
+                let slot_utc = resolve_local_datetime(local_naive, tz);
+                let slot_end = slot_utc + Duration::minutes(30);
+
+                // Buffer the same way the candidate conflict check does, so the grid
+                // agrees with what the suggester actually treats as free.
+                let busy_event = events.iter().find(|(e_start, e_end, _)| {
+                    let buffer_start = *e_start - Duration::minutes(args.buffer_mins);
+                    let buffer_end = *e_end + Duration::minutes(args.buffer_mins);
+                    slot_utc < buffer_end && slot_end > buffer_start
+                });
+
+                if let Some((_, _, summary)) = busy_event {
+                    let label = match (args.privacy, summary) {
+                        (Privacy::Private, Some(s)) => html_escape(s),
+                        _ => "Busy".to_string(),
+                    };
+                    out.push_str(&format!("<td class=\"busy\">{}</td>", label));
+                } else {
+                    let score = score_by_slot.get(&slot_utc).copied().unwrap_or(0);
+                    let class = if score == 1 { "free morning-peak" } else { "free" };
+                    out.push_str(&format!("<td class=\"{}\">free</td>", class));
+                }
+            }
+            out.push_str("</tr>\n");
+        }
+        hour_row += 1;
+    }
+
+    out.push_str("</table>\n</body>\n</html>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prop(name: &str, value: &str, params: Option<Vec<(&str, &str)>>) -> Property {
+        Property {
+            name: name.to_string(),
+            params: params.map(|ps| {
+                ps.into_iter()
+                    .map(|(k, v)| (k.to_string(), vec![v.to_string()]))
+                    .collect()
+            }),
+            value: Some(value.to_string()),
+        }
+    }
+
+    fn dt(y: i32, mo: u32, d: u32, h: u32, mi: u32, s: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, mo, d)
+            .unwrap()
+            .and_hms_opt(h, mi, s)
+            .unwrap()
+    }
+
+    #[test]
+    fn ics_datetime_parts_utc_z_suffix() {
+        let p = prop("DTSTART", "20240610T090000Z", None);
+        let (naive, zone) = ics_datetime_parts(&p, Tz::UTC).unwrap();
+        assert_eq!(naive, dt(2024, 6, 10, 9, 0, 0));
+        assert_eq!(zone, Tz::UTC);
+    }
+
+    #[test]
+    fn ics_datetime_parts_tzid_anchored() {
+        let p = prop(
+            "DTSTART",
+            "20240610T090000",
+            Some(vec![("TZID", "America/New_York")]),
+        );
+        let (naive, zone) = ics_datetime_parts(&p, Tz::UTC).unwrap();
+        assert_eq!(naive, dt(2024, 6, 10, 9, 0, 0));
+        assert_eq!(zone, chrono_tz::America::New_York);
+    }
+
+    #[test]
+    fn ics_datetime_parts_floating_uses_local_tz() {
+        let p = prop("DTSTART", "20240610T090000", None);
+        let (naive, zone) = ics_datetime_parts(&p, chrono_tz::Europe::Paris).unwrap();
+        assert_eq!(naive, dt(2024, 6, 10, 9, 0, 0));
+        assert_eq!(zone, chrono_tz::Europe::Paris);
+    }
+
+    #[test]
+    fn ics_datetime_parts_all_day() {
+        let p = prop("DTSTART", "20240610", None);
+        let (naive, zone) = ics_datetime_parts(&p, chrono_tz::Europe::Paris).unwrap();
+        assert_eq!(naive, dt(2024, 6, 10, 0, 0, 0));
+        assert_eq!(zone, chrono_tz::Europe::Paris);
+    }
+
+    #[test]
+    fn expand_rrule_weekly_byday_interval() {
+        // Every other week on Tue/Thu, starting Tuesday 2024-06-04.
+        let dtstart = dt(2024, 6, 4, 9, 0, 0);
+        let end_search = dt(2024, 7, 1, 0, 0, 0);
+        let occurrences = expand_rrule(
+            "FREQ=WEEKLY;INTERVAL=2;BYDAY=TU,TH",
+            dtstart,
+            Tz::UTC,
+            end_search,
+        );
+        assert_eq!(
+            occurrences,
+            vec![
+                dt(2024, 6, 4, 9, 0, 0),
+                dt(2024, 6, 6, 9, 0, 0),
+                dt(2024, 6, 18, 9, 0, 0),
+                dt(2024, 6, 20, 9, 0, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn expand_rrule_monthly_skips_non_existent_day_of_month() {
+        // DTSTART on the 31st: Feb (and other short months) have no 31st and must be
+        // skipped rather than truncating the whole expansion.
+        let dtstart = dt(2024, 1, 31, 9, 0, 0);
+        let end_search = dt(2024, 6, 1, 0, 0, 0);
+        let occurrences = expand_rrule("FREQ=MONTHLY;COUNT=4", dtstart, Tz::UTC, end_search);
+        assert_eq!(
+            occurrences,
+            vec![
+                dt(2024, 1, 31, 9, 0, 0),
+                dt(2024, 3, 31, 9, 0, 0),
+                // April has no 31st either, so May is next.
+                dt(2024, 5, 31, 9, 0, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn expand_rrule_respects_until() {
+        let dtstart = dt(2024, 6, 3, 9, 0, 0);
+        let end_search = dt(2024, 12, 31, 0, 0, 0);
+        let occurrences = expand_rrule(
+            "FREQ=DAILY;UNTIL=20240605T090000Z",
+            dtstart,
+            Tz::UTC,
+            end_search,
+        );
+        assert_eq!(
+            occurrences,
+            vec![
+                dt(2024, 6, 3, 9, 0, 0),
+                dt(2024, 6, 4, 9, 0, 0),
+                dt(2024, 6, 5, 9, 0, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn apply_exdates_filters_matching_occurrences() {
+        let occurrences = vec![
+            dt(2024, 6, 3, 9, 0, 0),
+            dt(2024, 6, 4, 9, 0, 0),
+            dt(2024, 6, 5, 9, 0, 0),
+        ];
+        let exdates = vec![dt(2024, 6, 4, 9, 0, 0)];
+        let filtered = apply_exdates(occurrences, &exdates);
+        assert_eq!(filtered, vec![dt(2024, 6, 3, 9, 0, 0), dt(2024, 6, 5, 9, 0, 0)]);
+    }
+
+    #[test]
+    fn local_day_at_stamps_hour_in_local_time_not_utc() {
+        // 2024-06-10T01:00:00Z is still 2024-06-09 local in New York (UTC-4 in June),
+        // so clamping to 23:00 local should land on the 9th, not the UTC-visible 10th.
+        let utc_naive = dt(2024, 6, 10, 1, 0, 0);
+        let clamped = local_day_at(utc_naive, chrono_tz::America::New_York, 23, 0, 0).unwrap();
+        assert_eq!(clamped, dt(2024, 6, 10, 3, 0, 0));
+    }
+
+    #[test]
+    fn parse_when_iso_datetime_and_date() {
+        let now = dt(2024, 6, 3, 12, 0, 0);
+        assert_eq!(
+            parse_when("2024-06-10T09:00:00", Tz::UTC, now),
+            Some(dt(2024, 6, 10, 9, 0, 0))
+        );
+        assert_eq!(
+            parse_when("2024-06-10", Tz::UTC, now),
+            Some(dt(2024, 6, 10, 0, 0, 0))
+        );
+    }
+
+    #[test]
+    fn parse_when_next_weekday() {
+        // 2024-06-03 is a Monday; "next monday" should land on the following Monday.
+        let now = dt(2024, 6, 3, 12, 0, 0);
+        assert_eq!(
+            parse_when("next monday", Tz::UTC, now),
+            Some(dt(2024, 6, 10, 0, 0, 0))
+        );
+    }
+
+    #[test]
+    fn parse_when_relative_in_and_plus() {
+        let now = dt(2024, 6, 3, 12, 0, 0);
+        assert_eq!(parse_when("in 3 days", Tz::UTC, now), Some(now + Duration::days(3)));
+        assert_eq!(parse_when("+2 weeks", Tz::UTC, now), Some(now + Duration::weeks(2)));
+    }
+
+    #[test]
+    fn compute_free_windows_merges_and_inverts() {
+        let start = dt(2024, 6, 3, 9, 0, 0);
+        let end = dt(2024, 6, 3, 18, 0, 0);
+        let events = vec![
+            (dt(2024, 6, 3, 10, 0, 0), dt(2024, 6, 3, 10, 30, 0), None),
+            // Overlaps the buffered tail of the first event once buffer_mins is applied.
+            (dt(2024, 6, 3, 10, 40, 0), dt(2024, 6, 3, 11, 0, 0), None),
+        ];
+        let free = compute_free_windows(&events, start, end, 15);
+        assert_eq!(
+            free,
+            vec![
+                (dt(2024, 6, 3, 9, 0, 0), dt(2024, 6, 3, 9, 45, 0)),
+                (dt(2024, 6, 3, 11, 15, 0), dt(2024, 6, 3, 18, 0, 0)),
+            ]
+        );
+    }
 }
\ No newline at end of file